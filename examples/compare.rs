@@ -6,11 +6,13 @@ use pleroma::{
 	debug::*,
 	keybindings::{keyboard::*, *},
 	pleroma::*,
+	screen::MAIN_TARGET,
 	structures::{
 		color::*,
 		font::Font,
 		image::Image,
 		misc::*,
+		rectangle::Rectangle,
 	}
 };
 
@@ -19,8 +21,8 @@ fn main() {
 	let mut pleroma: Pleroma = Pleroma::new();
 	pleroma.screen
 		.init("Pleroma Testing")
-		.set_resolution(800, 600)
-		.set_render_scale(0.5);
+		.set_resolution(MAIN_TARGET, 800, 600)
+		.set_render_scale(MAIN_TARGET, 0.5);
 	pleroma.fonts.insert("default".to_string(), Font::default());
 
 	pleroma.keys
@@ -50,8 +52,9 @@ fn main() {
 		}
 		if pleroma.keys.key_pressed("mod") { println!("Mod down") }
 
-		pleroma.screen.start_draw();
+		pleroma.screen.start_draw(MAIN_TARGET);
 		pleroma.textures.get("perlin").unwrap().draw(10, 10);
-		pleroma.screen.end_draw();
+		pleroma.screen.mark_dirty(MAIN_TARGET, Rectangle{ x: 10.0, y: 10.0, width: 64.0, height: 64.0 });
+		pleroma.screen.end_draw(MAIN_TARGET);
 	}
 }
\ No newline at end of file
@@ -0,0 +1,90 @@
+
+
+/// GamepadButton
+///
+/// Mirrors raylib's `GamepadButton` enum.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum GamepadButton {
+	LeftFaceUp,
+	LeftFaceRight,
+	LeftFaceDown,
+	LeftFaceLeft,
+	RightFaceUp,
+	RightFaceRight,
+	RightFaceDown,
+	RightFaceLeft,
+	LeftTrigger1,
+	LeftTrigger2,
+	RightTrigger1,
+	RightTrigger2,
+	MiddleLeft,
+	Middle,
+	MiddleRight,
+	LeftThumb,
+	RightThumb,
+}
+
+impl GamepadButton {
+	/// Converts to the raylib integer button code.
+	pub(crate) fn code(&self) -> i32 {
+		match self {
+			GamepadButton::LeftFaceUp => 1,
+			GamepadButton::LeftFaceRight => 2,
+			GamepadButton::LeftFaceDown => 3,
+			GamepadButton::LeftFaceLeft => 4,
+			GamepadButton::RightFaceUp => 5,
+			GamepadButton::RightFaceRight => 6,
+			GamepadButton::RightFaceDown => 7,
+			GamepadButton::RightFaceLeft => 8,
+			GamepadButton::LeftTrigger1 => 9,
+			GamepadButton::LeftTrigger2 => 10,
+			GamepadButton::RightTrigger1 => 11,
+			GamepadButton::RightTrigger2 => 12,
+			GamepadButton::MiddleLeft => 13,
+			GamepadButton::Middle => 14,
+			GamepadButton::MiddleRight => 15,
+			GamepadButton::LeftThumb => 16,
+			GamepadButton::RightThumb => 17,
+		}
+	}
+	/// Wrapper for IsGamepadButtonDown
+	pub(crate) fn is_down(&self, pad: i32) -> bool {
+		unsafe { IsGamepadButtonDown(pad, self.code()) }
+	}
+}
+
+/// GamepadAxis
+///
+/// Mirrors raylib's `GamepadAxis` enum.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum GamepadAxis {
+	LeftX,
+	LeftY,
+	RightX,
+	RightY,
+	LeftTrigger,
+	RightTrigger,
+}
+
+impl GamepadAxis {
+	/// Converts to the raylib integer axis code.
+	pub(crate) fn code(&self) -> i32 {
+		match self {
+			GamepadAxis::LeftX => 0,
+			GamepadAxis::LeftY => 1,
+			GamepadAxis::RightX => 2,
+			GamepadAxis::RightY => 3,
+			GamepadAxis::LeftTrigger => 4,
+			GamepadAxis::RightTrigger => 5,
+		}
+	}
+	/// Wrapper for GetGamepadAxisMovement, ignoring movement inside the deadzone.
+	pub(crate) fn value(&self, pad: i32) -> f32 {
+		const DEADZONE: f32 = 0.1;
+		let value = unsafe { GetGamepadAxisMovement(pad, self.code()) };
+		if value.abs() < DEADZONE { 0.0 } else { value }
+	}
+}
+
+extern "C" { fn IsGamepadButtonDown(gamepad: i32, button: i32) -> bool; }
+extern "C" { fn GetGamepadAxisMovement(gamepad: i32, axis: i32) -> f32; }
@@ -0,0 +1,84 @@
+
+
+/// KeyboardKey
+///
+/// Mirrors raylib's `KeyboardKey` enum so bindings can be declared without
+/// reaching for raw integer key codes.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum KeyboardKey {
+	Apostrophe,
+	Comma,
+	Minus,
+	Period,
+	Slash,
+	Zero, One, Two, Three, Four, Five, Six, Seven, Eight, Nine,
+	Semicolon,
+	Equal,
+	A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q, R, S, T, U, V, W, X, Y, Z,
+	Space,
+	Escape,
+	Enter,
+	Tab,
+	Backspace,
+	Insert,
+	Delete,
+	Right,
+	Left,
+	Down,
+	Up,
+	LeftShift,
+	LeftControl,
+	LeftAlt,
+	RightShift,
+	RightControl,
+	RightAlt,
+}
+
+impl KeyboardKey {
+	/// Converts to the raylib integer key code.
+	pub(crate) fn code(&self) -> i32 {
+		match self {
+			KeyboardKey::Apostrophe => 39,
+			KeyboardKey::Comma => 44,
+			KeyboardKey::Minus => 45,
+			KeyboardKey::Period => 46,
+			KeyboardKey::Slash => 47,
+			KeyboardKey::Zero => 48, KeyboardKey::One => 49, KeyboardKey::Two => 50,
+			KeyboardKey::Three => 51, KeyboardKey::Four => 52, KeyboardKey::Five => 53,
+			KeyboardKey::Six => 54, KeyboardKey::Seven => 55, KeyboardKey::Eight => 56,
+			KeyboardKey::Nine => 57,
+			KeyboardKey::Semicolon => 59,
+			KeyboardKey::Equal => 61,
+			KeyboardKey::A => 65, KeyboardKey::B => 66, KeyboardKey::C => 67, KeyboardKey::D => 68,
+			KeyboardKey::E => 69, KeyboardKey::F => 70, KeyboardKey::G => 71, KeyboardKey::H => 72,
+			KeyboardKey::I => 73, KeyboardKey::J => 74, KeyboardKey::K => 75, KeyboardKey::L => 76,
+			KeyboardKey::M => 77, KeyboardKey::N => 78, KeyboardKey::O => 79, KeyboardKey::P => 80,
+			KeyboardKey::Q => 81, KeyboardKey::R => 82, KeyboardKey::S => 83, KeyboardKey::T => 84,
+			KeyboardKey::U => 85, KeyboardKey::V => 86, KeyboardKey::W => 87, KeyboardKey::X => 88,
+			KeyboardKey::Y => 89, KeyboardKey::Z => 90,
+			KeyboardKey::Space => 32,
+			KeyboardKey::Escape => 256,
+			KeyboardKey::Enter => 257,
+			KeyboardKey::Tab => 258,
+			KeyboardKey::Backspace => 259,
+			KeyboardKey::Insert => 260,
+			KeyboardKey::Delete => 261,
+			KeyboardKey::Right => 262,
+			KeyboardKey::Left => 263,
+			KeyboardKey::Down => 264,
+			KeyboardKey::Up => 265,
+			KeyboardKey::LeftShift => 340,
+			KeyboardKey::LeftControl => 341,
+			KeyboardKey::LeftAlt => 342,
+			KeyboardKey::RightShift => 344,
+			KeyboardKey::RightControl => 345,
+			KeyboardKey::RightAlt => 346,
+		}
+	}
+	/// Wrapper for IsKeyDown
+	pub(crate) fn is_down(&self) -> bool {
+		unsafe { IsKeyDown(self.code()) }
+	}
+}
+
+extern "C" { fn IsKeyDown(key: i32) -> bool; }
@@ -0,0 +1,86 @@
+
+
+use std::collections::HashMap;
+
+pub mod keyboard;
+pub mod gamepad;
+
+use keyboard::*;
+use gamepad::*;
+
+
+/// Binding
+///
+/// A single input source a keybinding can resolve to. A named binding is a
+/// `Vec<Binding>`, all of which must be active at once (a chord), so e.g. a
+/// "mod" binding can require `LeftShift` and `S` held together.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Binding {
+	KeyboardKey(KeyboardKey),
+	GamepadButton(i32, GamepadButton),
+	/// Pad index, axis, and the signed threshold the analog value must cross to
+	/// count as "pressed": a positive threshold fires when the value rises to or
+	/// past it, a negative threshold fires when the value falls to or past it, so
+	/// opposite stick directions can be bound separately (deadzone handling lives
+	/// in `GamepadAxis::value`).
+	GamepadAxis(i32, GamepadAxis, f32),
+}
+
+impl Binding {
+	/// Whether this individual binding is currently active.
+	fn is_down(&self) -> bool {
+		match self {
+			Binding::KeyboardKey(key) => key.is_down(),
+			Binding::GamepadButton(pad, button) => button.is_down(*pad),
+			Binding::GamepadAxis(pad, axis, threshold) => {
+				let value = axis.value(*pad);
+				if *threshold >= 0.0 { value >= *threshold } else { value <= *threshold }
+			},
+		}
+	}
+	/// The continuous value of this binding, for bindings that have one.
+	fn axis_value(&self) -> f32 {
+		match self {
+			Binding::GamepadAxis(pad, axis, _) => axis.value(*pad),
+			_ => 0.0,
+		}
+	}
+}
+
+/// Keybindings
+///
+/// Named chords of `Binding`s that games look up by name instead of hardcoding
+/// raw key/button/axis checks at every call site.
+pub struct Keybindings {
+	bindings: HashMap<String, Vec<Binding>>,
+}
+
+impl Keybindings {
+	//= Creation
+	/// Creates an empty set of keybindings.
+	pub fn new() -> Self {
+		Self { bindings: HashMap::new() }
+	}
+
+	//= Manipulation
+	/// Registers (or replaces) the chord of bindings for `name`.
+	pub fn insert(&mut self, name: &str, bindings: Vec<Binding>) -> &mut Self {
+		self.bindings.insert(name.to_string(), bindings);
+		self
+	}
+	/// Whether every binding registered under `name` is currently active.
+	pub fn key_pressed(&self, name: &str) -> bool {
+		match self.bindings.get(name) {
+			Some(bindings) => !bindings.is_empty() && bindings.iter().all(|binding| binding.is_down()),
+			None => false,
+		}
+	}
+	/// The value of the first of `name`'s axis bindings that is outside its deadzone,
+	/// or `0.0` if it has no axis binding or all of them are inside the deadzone.
+	pub fn axis_value(&self, name: &str) -> f32 {
+		match self.bindings.get(name) {
+			Some(bindings) => bindings.iter().map(|binding| binding.axis_value()).find(|value| *value != 0.0).unwrap_or(0.0),
+			None => 0.0,
+		}
+	}
+}
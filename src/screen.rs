@@ -1,249 +1,519 @@
-
-
-use crate::{
-	debug::*,
-	rl_str,
-	structures::{
-		color::{self, *},
-		font::*,
-		misc::clear_background,
-		rectangle::*,
-		render_texture::*,
-		texture::*,
-		vectors::*,
-	},
-};
-
-
-/// WindowState
-#[derive(Debug, PartialEq)]
-pub enum WindowState {
-	Windowed,
-	Fullscreen,
-	Borderless,
-}
-
-/// Resolution
-#[derive(Debug, PartialEq)]
-pub struct Resolution {
-	pub width: i32,
-	pub height: i32,
-}
-
-/// Screen data
-pub struct Screen {
-	pub screen: Resolution,
-	pub window_state: WindowState,
-
-	pub render: Resolution,
-	pub render_ratio: f32,
-	pub render_texture: Option<RenderTexture>,
-
-	pub raylib_init: bool,
-	pub background_color: Color,
-	pub framerate: i32,
-	
-	pub def_font: Font,
-}
-
-
-/// Default: screen_width
-pub const DEF_SCREEN_WIDTH: i32 = 1280;
-/// Default: screen_height
-pub const DEF_SCREEN_HEIGHT: i32 = 720;
-
-
-impl Screen {
-	
-	//= Creation
-	/// Creates basic structure for Screen
-	pub fn new() -> Self {
-		Self {
-			screen: Resolution{
-				width:		DEF_SCREEN_WIDTH,
-				height:		DEF_SCREEN_HEIGHT,
-			},
-
-			window_state:	WindowState::Windowed,
-
-			render: Resolution{
-				width:		DEF_SCREEN_WIDTH,
-				height:		DEF_SCREEN_HEIGHT,
-			},
-			render_ratio:	1.0,
-			render_texture: None,
-
-			raylib_init:	false,
-			background_color: color::DARKGRAY,
-			framerate: 60,
-
-			def_font: Font::default(),
-		}
-	}
-
-	//= Manipulation
-	/// Wrapper for InitWindow telling the screen that raylib is now on and update render.
-	pub fn init(&mut self, title: &str) -> &mut Self {
-		unsafe {
-			SetTraceLogLevel(7);
-			InitWindow(self.screen.width, self.screen.height, rl_str!(title));
-			SetTargetFPS(self.framerate);
-			SetTextLineSpacing(9);
-		}
-		self.raylib_init = true;
-
-		self.update_render()
-	}
-	/// Wrapper for CloseWindow that tells the screen that raylib is off
-	pub fn close(&mut self) {
-		unsafe { CloseWindow() }
-
-		if self.render_texture.is_some() { self.render_texture.as_mut().unwrap().unload() }
-		self.raylib_init = false;
-	}
-	/// Wrapper for IsWindowReady
-	pub fn window_ready(&self) -> bool {
-		if self.raylib_init { unsafe { IsWindowReady() } }
-		else { false }
-	}
-	/// Wrapper for ToggleFullscreen
-	pub fn toggle_fullscreen(&mut self) {
-		if self.window_state != WindowState::Fullscreen {
-			self.window_state = WindowState::Fullscreen
-		} else { self.window_state = WindowState::Windowed }
-		unsafe {
-			ToggleFullscreen();
-			self.window_state = WindowState::Fullscreen;
-			self.screen.width = GetScreenWidth();
-			self.screen.height = GetScreenHeight();
-			self.update_render();
-		}
-	}
-	/// Wrapper for ToggleBorderlessWindowed
-	pub fn toggle_borderless(&mut self) {
-		if self.window_state != WindowState::Borderless {
-			self.window_state = WindowState::Borderless
-		} else { self.window_state = WindowState::Windowed }
-		unsafe {
-			ToggleBorderlessWindowed();
-			self.window_state = WindowState::Borderless;
-			self.screen.width = GetScreenWidth();
-			self.screen.height = GetScreenHeight();
-			self.update_render();
-		}
-	}
-	/// Wrapper for SetWindowSize
-	pub fn set_resolution(&mut self, width: i32, height: i32) -> &mut Self {
-		self.screen.width = width;
-		self.screen.height = height;
-
-		self.render.width = ((width as f32) * self.render_ratio) as i32;
-		self.render.height = ((height as f32) * self.render_ratio) as i32;
-
-		if self.raylib_init { unsafe { SetWindowSize(width, height) } }
-
-		self.update_render()
-	}
-	/// Sets the render scale and creates a new render texture for that resolution.
-	pub fn set_render_scale(&mut self, scale: f32) -> &mut Self {
-		self.render_ratio = scale;
-		self.render.width = ((self.screen.width as f32) * self.render_ratio) as i32;
-		self.render.height = ((self.screen.height as f32) * self.render_ratio) as i32;
-
-		self.update_render();
-
-		self
-	}
-	/// Starts rendering to texture if it exists
-	pub fn start_draw(&mut self) {
-		if self.render_texture.is_none() {
-			// TODO: Error reporting
-			return;
-		}
-
-		self.render_texture.as_mut().unwrap().begin_texture_mode();
-		clear_background(self.background_color.into());
-	}
-	/// End rendering to texture if it exists and draws it to screen
-	pub fn end_draw(&mut self) {
-		//* Check if RenderTexture exists */
-		if self.render_texture.is_none() { log(Error::RenderTextureDoesntExist); return; }
-		
-		unsafe {
-			//* Draw debug display */
-			if DEBUG_DISPLAY { self.draw_debug(); }
-			
-			//* Draw error log */
-			if DEBUG_LOG.is_some() {
-				let mut count = 0;
-				let mut list: Vec<i32> = Vec::new();
-				for i in DEBUG_LOG.as_mut().unwrap().as_mut_slice().into_iter() {
-					i.1 -= 1;
-					if i.1 <= 0 { list.push(count) }
-					else {
-						let height = self.render.height as f32 - 8.0 - (10.0 * count as f32);
-						self.def_font.draw(&i.0, Vector2 { x: 0.0, y: height }, 8.0, 1.0, BLACK);
-						count += 1;
-					}
-				}
-				list.reverse();
-				for i in list { DEBUG_LOG.as_mut().unwrap().remove(i as usize); }
-			}
-		}
-
-
-		//* Draw RenderTexture to screen */
-		self.render_texture.as_mut().unwrap().end_texture_mode();
-		unsafe {
-			BeginDrawing();
-
-			Texture(self.render_texture.as_mut().unwrap().0.texture, WHITE).draw_pro(
-				Rectangle{
-					x: 0.0,
-					y: 0.0,
-					width: self.render.width as f32,
-					height: -self.render.height as f32,
-				},
-				Rectangle{
-					x: 0.0,
-					y: 0.0,
-					width: self.screen.width as f32,
-					height: self.screen.height as f32,
-				},
-				Vector2{x: 0.0, y: 0.0},
-				0.0,
-			);
-			
-			EndDrawing();
-		}
-	}
-	/// Unloads previous texture if it exists and ends the drawing cycle
-	fn update_render(&mut self) -> &mut Self {
-		if self.render_texture.is_some() { self.render_texture.as_mut().unwrap().unload() }
-		if self.raylib_init { self.render_texture = Some(RenderTexture::load(self.render.width, self.render.height)) }
-		
-		self
-	}
-	/// Sets target FPS
-	pub fn set_fps(&mut self, fps: i32) {
-		unsafe{
-			self.framerate = fps;
-			SetTargetFPS(self.framerate);
-		}
-	}
-
-}
-
-extern "C" { fn InitWindow(width: i32, height: i32, title: *const i8); }
-extern "C" { fn SetTraceLogLevel(logLevel: i32); }
-extern "C" { fn CloseWindow(); }
-extern "C" { fn IsWindowReady() -> bool; }
-extern "C" { fn ToggleFullscreen(); }
-extern "C" { fn ToggleBorderlessWindowed(); }
-extern "C" { fn GetScreenWidth() -> i32; }
-extern "C" { fn GetScreenHeight() -> i32; }
-extern "C" { fn SetWindowSize(width: i32, height: i32); }
-extern "C" { fn BeginDrawing(); }
-extern "C" { fn EndDrawing(); }
-extern "C" { fn SetTargetFPS(fps: i32); }
-extern "C" { fn SetTextLineSpacing(spacing: i32); }
\ No newline at end of file
+
+
+use std::collections::HashMap;
+
+use crate::{
+	debug::*,
+	rl_str,
+	structures::{
+		color::{self, *},
+		font::*,
+		misc::clear_background,
+		rectangle::*,
+		render_texture::*,
+		texture::*,
+		vectors::*,
+	},
+};
+
+
+/// WindowState
+#[derive(Debug, PartialEq)]
+pub enum WindowState {
+	Windowed,
+	Fullscreen,
+	Borderless,
+	/// The OS window is created hidden and never shown or presented to; frames are
+	/// rendered straight to the `RenderTexture` for offscreen use (CI screenshots,
+	/// thumbnail generation, etc). A display server is still needed for that window.
+	Headless,
+}
+
+/// Resolution
+#[derive(Debug, PartialEq)]
+pub struct Resolution {
+	pub width: i32,
+	pub height: i32,
+}
+
+/// StartupMode
+///
+/// Declares how the window should appear the moment it is created, so the
+/// desired geometry is already in place before the first frame is drawn
+/// instead of being toggled on after a plain windowed launch.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum StartupMode {
+	Windowed,
+	Maximized,
+	Fullscreen,
+	Borderless,
+}
+
+/// Handle identifying one of a `Screen`'s render targets.
+pub type TargetHandle = String;
+/// The render target backing the primary window, always present on a `Screen`.
+pub const MAIN_TARGET: &str = "main";
+
+/// RenderTarget
+///
+/// An independent render surface: its own resolution, scale, background, texture
+/// and damage tracking. A `Screen` always has `MAIN_TARGET`; games can add more
+/// via `Screen::add_target` to open auxiliary surfaces alongside the main window.
+pub struct RenderTarget {
+	pub render: Resolution,
+	pub render_ratio: f32,
+	pub render_texture: Option<RenderTexture>,
+	pub background_color: Color,
+
+	/// Regions of this target's render texture touched since the last present.
+	/// `end_draw` only checks whether this is non-empty to decide whether a
+	/// present is needed at all; see `end_draw` for why the individual rects
+	/// aren't used to blit a sub-region.
+	pub dirty_rects: Vec<Rectangle>,
+}
+
+impl RenderTarget {
+	fn new(width: i32, height: i32, background_color: Color) -> Self {
+		Self {
+			render: Resolution{ width, height },
+			render_ratio: 1.0,
+			render_texture: None,
+			background_color,
+
+			dirty_rects: Vec::new(),
+		}
+	}
+}
+
+/// Screen data
+pub struct Screen {
+	pub screen: Resolution,
+	pub window_state: WindowState,
+
+	pub raylib_init: bool,
+	pub framerate: i32,
+
+	pub startup_mode: StartupMode,
+	pub window_position: Option<(i32, i32)>,
+
+	pub def_font: Font,
+
+	targets: HashMap<TargetHandle, RenderTarget>,
+}
+
+
+/// Default: screen_width
+pub const DEF_SCREEN_WIDTH: i32 = 1280;
+/// Default: screen_height
+pub const DEF_SCREEN_HEIGHT: i32 = 720;
+
+
+impl Screen {
+
+	//= Creation
+	/// Creates basic structure for Screen
+	pub fn new() -> Self {
+		let mut targets = HashMap::new();
+		targets.insert(MAIN_TARGET.to_string(), RenderTarget::new(DEF_SCREEN_WIDTH, DEF_SCREEN_HEIGHT, color::DARKGRAY));
+
+		Self {
+			screen: Resolution{
+				width:		DEF_SCREEN_WIDTH,
+				height:		DEF_SCREEN_HEIGHT,
+			},
+
+			window_state:	WindowState::Windowed,
+
+			raylib_init:	false,
+			framerate: 60,
+
+			startup_mode: StartupMode::Windowed,
+			window_position: None,
+
+			def_font: Font::default(),
+
+			targets,
+		}
+	}
+
+	//= Targets
+	/// Opens an auxiliary render target with its own resolution and background,
+	/// routed to through `start_draw`/`end_draw` by handle alongside the main window.
+	pub fn add_target(&mut self, handle: &str, width: i32, height: i32, background_color: Color) -> &mut Self {
+		self.targets.insert(handle.to_string(), RenderTarget::new(width, height, background_color));
+
+		if self.raylib_init { self.update_render(handle); }
+
+		self
+	}
+	/// Unloads and drops a render target. `MAIN_TARGET` cannot be removed.
+	pub fn remove_target(&mut self, handle: &str) {
+		if handle == MAIN_TARGET { return; }
+
+		if let Some(mut target) = self.targets.remove(handle) {
+			if target.render_texture.is_some() { target.render_texture.as_mut().unwrap().unload() }
+		}
+	}
+	/// Read access to a render target's resolution, scale, texture and background.
+	pub fn target(&self, handle: &str) -> Option<&RenderTarget> {
+		self.targets.get(handle)
+	}
+	/// Mutable access to a render target's resolution, scale, texture and background.
+	pub fn target_mut(&mut self, handle: &str) -> Option<&mut RenderTarget> {
+		self.targets.get_mut(handle)
+	}
+
+	//= Manipulation
+	/// Sets the window mode (maximized / fullscreen / borderless / centered windowed)
+	/// to apply on the next `init`, so the window appears in its final geometry
+	/// immediately instead of flashing windowed first and toggling afterwards.
+	pub fn set_startup_mode(&mut self, mode: StartupMode) -> &mut Self {
+		self.startup_mode = mode;
+		self
+	}
+	/// Sets the window position to apply on the next `init`.
+	pub fn set_window_position(&mut self, x: i32, y: i32) -> &mut Self {
+		self.window_position = Some((x, y));
+		self
+	}
+	/// Wrapper for InitWindow telling the screen that raylib is now on and update render.
+	pub fn init(&mut self, title: &str) -> &mut Self {
+		unsafe {
+			SetTraceLogLevel(7);
+
+			match self.startup_mode {
+				StartupMode::Windowed => {},
+				StartupMode::Maximized => SetConfigFlags(FLAG_WINDOW_MAXIMIZED),
+				StartupMode::Fullscreen => SetConfigFlags(FLAG_FULLSCREEN_MODE),
+				StartupMode::Borderless => SetConfigFlags(FLAG_BORDERLESS_WINDOWED_MODE),
+			}
+
+			InitWindow(self.screen.width, self.screen.height, rl_str!(title));
+			SetTargetFPS(self.framerate);
+			SetTextLineSpacing(9);
+
+			if let Some((x, y)) = self.window_position { SetWindowPosition(x, y); }
+
+			self.window_state = match self.startup_mode {
+				StartupMode::Windowed | StartupMode::Maximized => WindowState::Windowed,
+				StartupMode::Fullscreen => WindowState::Fullscreen,
+				StartupMode::Borderless => WindowState::Borderless,
+			};
+
+			self.screen.width = GetScreenWidth();
+			self.screen.height = GetScreenHeight();
+		}
+		self.raylib_init = true;
+
+		self.update_all_targets()
+	}
+	/// Sets the screen up for offscreen rendering: it still calls `InitWindow` (hidden
+	/// via `FLAG_WINDOW_HIDDEN`) so the GL context backing `RenderTexture::load` actually
+	/// exists, but the window is never shown and `end_draw` never blits to it. Skips
+	/// `BeginDrawing`/`EndDrawing`; frames can be rendered and read back
+	/// (e.g. `self.target(MAIN_TARGET).unwrap().render_texture.as_ref().unwrap().0.texture`)
+	/// for automated screenshot and thumbnail tooling.
+	pub fn init_headless(&mut self, width: i32, height: i32) -> &mut Self {
+		self.window_state = WindowState::Headless;
+		self.screen.width = width;
+		self.screen.height = height;
+
+		unsafe {
+			SetTraceLogLevel(7);
+			SetConfigFlags(FLAG_WINDOW_HIDDEN);
+			InitWindow(width, height, rl_str!(""));
+			SetTextLineSpacing(9);
+		}
+
+		let target = self.targets.get_mut(MAIN_TARGET).unwrap();
+		target.render.width = ((width as f32) * target.render_ratio) as i32;
+		target.render.height = ((height as f32) * target.render_ratio) as i32;
+
+		self.raylib_init = true;
+
+		self.update_all_targets()
+	}
+	/// Wrapper for CloseWindow that tells the screen that raylib is off
+	pub fn close(&mut self) {
+		unsafe { CloseWindow() }
+
+		for target in self.targets.values_mut() {
+			if target.render_texture.is_some() { target.render_texture.as_mut().unwrap().unload() }
+		}
+		self.raylib_init = false;
+	}
+	/// Wrapper for IsWindowReady
+	pub fn window_ready(&self) -> bool {
+		if self.raylib_init { unsafe { IsWindowReady() } }
+		else { false }
+	}
+	/// Wrapper for ToggleFullscreen. `target` is the render target resized to the
+	/// new window dimensions (almost always `MAIN_TARGET`; auxiliary targets keep
+	/// their own resolution unless explicitly toggled).
+	pub fn toggle_fullscreen(&mut self, target: &str) {
+		if self.window_state != WindowState::Fullscreen {
+			self.window_state = WindowState::Fullscreen
+		} else { self.window_state = WindowState::Windowed }
+		unsafe {
+			ToggleFullscreen();
+			self.window_state = WindowState::Fullscreen;
+			self.screen.width = GetScreenWidth();
+			self.screen.height = GetScreenHeight();
+			self.resize_target_to_screen(target);
+			self.update_render(target);
+		}
+	}
+	/// Wrapper for ToggleBorderlessWindowed. See `toggle_fullscreen` for `target`.
+	pub fn toggle_borderless(&mut self, target: &str) {
+		if self.window_state != WindowState::Borderless {
+			self.window_state = WindowState::Borderless
+		} else { self.window_state = WindowState::Windowed }
+		unsafe {
+			ToggleBorderlessWindowed();
+			self.window_state = WindowState::Borderless;
+			self.screen.width = GetScreenWidth();
+			self.screen.height = GetScreenHeight();
+			self.resize_target_to_screen(target);
+			self.update_render(target);
+		}
+	}
+	/// Wrapper for SetWindowSize. `target` is the render target resized to the new
+	/// window dimensions (almost always `MAIN_TARGET`).
+	pub fn set_resolution(&mut self, target: &str, width: i32, height: i32) -> &mut Self {
+		self.screen.width = width;
+		self.screen.height = height;
+
+		self.resize_target_to_screen(target);
+
+		if self.raylib_init { unsafe { SetWindowSize(width, height) } }
+
+		self.update_render(target)
+	}
+	/// Sets a target's render scale and creates a new render texture for that resolution.
+	pub fn set_render_scale(&mut self, target: &str, scale: f32) -> &mut Self {
+		{
+			let Some(t) = self.targets.get_mut(target) else {
+				// TODO: Error reporting
+				return self;
+			};
+			t.render_ratio = scale;
+		}
+		self.resize_target_to_screen(target);
+
+		self.update_render(target);
+
+		self
+	}
+	/// Resizes `target`'s render resolution to the current screen size scaled by
+	/// its own `render_ratio`. No-ops on an unknown handle.
+	fn resize_target_to_screen(&mut self, target: &str) {
+		let width = self.screen.width;
+		let height = self.screen.height;
+		let Some(t) = self.targets.get_mut(target) else {
+			// TODO: Error reporting
+			return;
+		};
+		t.render.width = ((width as f32) * t.render_ratio) as i32;
+		t.render.height = ((height as f32) * t.render_ratio) as i32;
+	}
+	/// Marks a region of `target`'s render texture as dirty so `end_draw` re-presents
+	/// it. Draw calls should report the area they touched through this instead of
+	/// relying on a full-screen redraw every frame.
+	pub fn mark_dirty(&mut self, target: &str, rect: Rectangle) {
+		if let Some(t) = self.targets.get_mut(target) { t.dirty_rects.push(rect); }
+	}
+	/// Marks the entirety of `target`'s render texture as dirty, e.g. after a
+	/// resolution change. No-ops on an unknown handle.
+	fn mark_full_damage(&mut self, target: &str) {
+		let Some(t) = self.targets.get_mut(target) else {
+			// TODO: Error reporting
+			return;
+		};
+		t.dirty_rects = vec![Rectangle{
+			x: 0.0,
+			y: 0.0,
+			width: t.render.width as f32,
+			height: t.render.height as f32,
+		}];
+	}
+	/// Starts rendering to `target`'s texture if it exists. The background clear is
+	/// itself a draw call that touches every pixel, so it automatically marks the
+	/// whole target dirty; callers drawing on top of it should still report any
+	/// area they touch beyond that through `mark_dirty` (see the debug overlay in
+	/// `end_draw` for an example) so a target left un-cleared between frames still
+	/// gets its changed regions re-presented.
+	pub fn start_draw(&mut self, target: &str) {
+		let Some(t) = self.targets.get_mut(target) else {
+			// TODO: Error reporting
+			return;
+		};
+		if t.render_texture.is_none() {
+			// TODO: Error reporting
+			return;
+		}
+
+		t.render_texture.as_mut().unwrap().begin_texture_mode();
+		clear_background(t.background_color.into());
+
+		let width = t.render.width as f32;
+		let height = t.render.height as f32;
+		self.mark_dirty(target, Rectangle{ x: 0.0, y: 0.0, width, height });
+	}
+	/// Starts rendering to `target`'s texture without clearing it first. Use this
+	/// instead of `start_draw` for targets that redraw only a few elements on top
+	/// of an otherwise unchanged background (e.g. a HUD); since nothing is cleared,
+	/// no damage is marked automatically, so callers must report every region they
+	/// touch through `mark_dirty`. This is what lets `end_draw` skip re-blitting the
+	/// texture on a frame where nothing changed (it still runs `BeginDrawing`/`EndDrawing`).
+	pub fn start_draw_no_clear(&mut self, target: &str) {
+		let Some(t) = self.targets.get_mut(target) else {
+			// TODO: Error reporting
+			return;
+		};
+		if t.render_texture.is_none() {
+			// TODO: Error reporting
+			return;
+		}
+
+		t.render_texture.as_mut().unwrap().begin_texture_mode();
+	}
+	/// End rendering to `target`'s texture if it exists and, for `MAIN_TARGET`,
+	/// presents the whole texture to screen when anything was marked dirty this
+	/// frame. Other targets are offscreen surfaces games read back or blit
+	/// themselves, so they're never presented.
+	///
+	/// raylib double-buffers and swaps on `EndDrawing`, so a blit covering only
+	/// the dirtied sub-rects would leave undamaged regions showing whatever was
+	/// on the *other* buffer (two frames ago), not this frame's unchanged
+	/// content — visibly flickering. Dirty tracking is therefore only used to
+	/// decide *whether* to re-blit, not to shrink *how much* of the texture is
+	/// blitted; the whole texture is always re-presented on a dirty frame.
+	pub fn end_draw(&mut self, target: &str) {
+		let Some(t) = self.targets.get_mut(target) else {
+			log(Error::RenderTextureDoesntExist);
+			return;
+		};
+
+		//* Check if RenderTexture exists */
+		if t.render_texture.is_none() { log(Error::RenderTextureDoesntExist); return; }
+
+		if target == MAIN_TARGET {
+			unsafe {
+				//* Draw debug display */
+				if DEBUG_DISPLAY { self.draw_debug(); }
+
+				//* Draw error log */
+				if DEBUG_LOG.is_some() {
+					let mut count = 0;
+					let mut list: Vec<i32> = Vec::new();
+					for i in DEBUG_LOG.as_mut().unwrap().as_mut_slice().into_iter() {
+						i.1 -= 1;
+						if i.1 <= 0 { list.push(count) }
+						else {
+							let t = self.targets.get_mut(MAIN_TARGET).unwrap();
+							let height = t.render.height as f32 - 8.0 - (10.0 * count as f32);
+							let width = t.render.width as f32;
+							self.def_font.draw(&i.0, Vector2 { x: 0.0, y: height }, 8.0, 1.0, BLACK);
+							self.mark_dirty(MAIN_TARGET, Rectangle{ x: 0.0, y: height, width, height: 8.0 });
+							count += 1;
+						}
+					}
+					list.reverse();
+					for i in list { DEBUG_LOG.as_mut().unwrap().remove(i as usize); }
+				}
+			}
+		}
+
+		let t = self.targets.get_mut(target).unwrap();
+
+		//* Draw RenderTexture to screen */
+		t.render_texture.as_mut().unwrap().end_texture_mode();
+
+		//* Headless, or an auxiliary target with no OS window to present to */
+		if self.window_state == WindowState::Headless || target != MAIN_TARGET {
+			self.targets.get_mut(target).unwrap().dirty_rects.clear();
+			return;
+		}
+
+		let t = self.targets.get_mut(target).unwrap();
+		let has_damage = !t.dirty_rects.is_empty();
+		t.dirty_rects.clear();
+		let render_width = t.render.width as f32;
+		let render_height = t.render.height as f32;
+		let screen_width = self.screen.width as f32;
+		let screen_height = self.screen.height as f32;
+
+		unsafe {
+			//* BeginDrawing/EndDrawing run every frame regardless of damage: EndDrawing is
+			//* what pumps PollInputEvents, so skipping it on a quiet frame would stall input
+			//* handling and the OS would eventually flag the window unresponsive. */
+			BeginDrawing();
+
+			if has_damage {
+				//* The render texture is flipped vertically (OpenGL convention). */
+				Texture(t.render_texture.as_mut().unwrap().0.texture, WHITE).draw_pro(
+					Rectangle{ x: 0.0, y: 0.0, width: render_width, height: -render_height },
+					Rectangle{ x: 0.0, y: 0.0, width: screen_width, height: screen_height },
+					Vector2{x: 0.0, y: 0.0},
+					0.0,
+				);
+			}
+
+			EndDrawing();
+		}
+	}
+	/// Allocates render textures for every registered target, not just `MAIN_TARGET`,
+	/// so targets added via `add_target` before `init`/`init_headless` runs don't get
+	/// left with `render_texture` permanently `None`.
+	fn update_all_targets(&mut self) -> &mut Self {
+		let handles: Vec<TargetHandle> = self.targets.keys().cloned().collect();
+		for handle in handles { self.update_render(&handle); }
+		self
+	}
+	/// Unloads a target's previous texture if it exists and allocates a fresh one
+	/// at its current render resolution.
+	fn update_render(&mut self, target: &str) -> &mut Self {
+		let raylib_init = self.raylib_init;
+		if let Some(t) = self.targets.get_mut(target) {
+			if t.render_texture.is_some() { t.render_texture.as_mut().unwrap().unload() }
+			if raylib_init { t.render_texture = Some(RenderTexture::load(t.render.width, t.render.height)) }
+		}
+
+		self.mark_full_damage(target);
+
+		self
+	}
+	/// Sets target FPS
+	pub fn set_fps(&mut self, fps: i32) {
+		unsafe{
+			self.framerate = fps;
+			SetTargetFPS(self.framerate);
+		}
+	}
+
+}
+
+/// Flag: FLAG_FULLSCREEN_MODE
+const FLAG_FULLSCREEN_MODE: u32 = 0x00000002;
+/// Flag: FLAG_WINDOW_MAXIMIZED
+const FLAG_WINDOW_MAXIMIZED: u32 = 0x00000400;
+/// Flag: FLAG_BORDERLESS_WINDOWED_MODE
+const FLAG_BORDERLESS_WINDOWED_MODE: u32 = 0x00008000;
+/// Flag: FLAG_WINDOW_HIDDEN
+const FLAG_WINDOW_HIDDEN: u32 = 0x00000080;
+
+extern "C" { fn InitWindow(width: i32, height: i32, title: *const i8); }
+extern "C" { fn SetTraceLogLevel(logLevel: i32); }
+extern "C" { fn SetConfigFlags(flags: u32); }
+extern "C" { fn SetWindowPosition(x: i32, y: i32); }
+extern "C" { fn CloseWindow(); }
+extern "C" { fn IsWindowReady() -> bool; }
+extern "C" { fn ToggleFullscreen(); }
+extern "C" { fn ToggleBorderlessWindowed(); }
+extern "C" { fn GetScreenWidth() -> i32; }
+extern "C" { fn GetScreenHeight() -> i32; }
+extern "C" { fn SetWindowSize(width: i32, height: i32); }
+extern "C" { fn BeginDrawing(); }
+extern "C" { fn EndDrawing(); }
+extern "C" { fn SetTargetFPS(fps: i32); }
+extern "C" { fn SetTextLineSpacing(spacing: i32); }
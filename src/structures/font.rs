@@ -0,0 +1,133 @@
+
+
+use std::collections::HashMap;
+
+use crate::structures::{color::*, vectors::*};
+
+
+/// Font
+///
+/// Wraps a raylib font. `draw` is the cheap per-codepoint legacy path; `draw_shaped`
+/// opts into a per-glyph draw path that advances the pen by each glyph's own cached
+/// width instead of snapping the whole run to `DrawTextEx`'s internal integer layout.
+///
+/// Note: this does not do script-aware shaping (ligature substitution, combining-mark
+/// composition, bidi/script reordering) — that needs a real shaping engine (e.g.
+/// rustybuzz), which isn't a dependency this crate currently pulls in. It also does not
+/// rasterize glyphs at subpixel offsets — glyph bitmaps still come from raylib's atlas
+/// via `DrawTextCodepoint`, which snaps to integer pixel origins; only the pen position
+/// between glyphs is tracked at fractional precision. What's actually implemented is
+/// per-codepoint glyph lookup via raylib's own atlas, with the glyph index and advance
+/// metrics cached per codepoint so they aren't re-measured every frame.
+pub struct Font {
+	pub(crate) raylib_font: RLFont,
+	base_size: f32,
+
+	shaping: bool,
+	glyph_cache: HashMap<u32, GlyphMetrics>,
+}
+
+/// A codepoint's glyph index and advance width at `base_size`, cached so repeated
+/// draws of the same text don't re-resolve them every frame.
+#[derive(Clone, Copy)]
+struct GlyphMetrics {
+	glyph_id: i32,
+	advance: f32,
+}
+
+impl Font {
+	//= Creation
+	/// Wrapper for GetFontDefault
+	pub fn default() -> Self {
+		Self {
+			raylib_font: unsafe { GetFontDefault() },
+			base_size: 10.0,
+
+			shaping: false,
+			glyph_cache: HashMap::new(),
+		}
+	}
+	/// Wrapper for LoadFont
+	pub fn load(path: &str) -> Self {
+		Self {
+			raylib_font: unsafe { LoadFont(crate::rl_str!(path)) },
+			base_size: 10.0,
+
+			shaping: false,
+			glyph_cache: HashMap::new(),
+		}
+	}
+
+	//= Manipulation
+	/// Enables or disables the per-glyph draw path used by `draw_shaped`. Toggling
+	/// this invalidates the glyph cache, since a font change can change glyph indices.
+	pub fn set_shaping(&mut self, enabled: bool) -> &mut Self {
+		self.shaping = enabled;
+		self.glyph_cache.clear();
+		self
+	}
+	/// Sets the base size glyph metrics are measured at and invalidates the glyph
+	/// cache, since cached advances are only valid for the size they were measured at.
+	pub fn set_base_size(&mut self, base_size: f32) -> &mut Self {
+		self.base_size = base_size;
+		self.glyph_cache.clear();
+		self
+	}
+
+	//= Drawing
+	/// Wrapper for DrawTextEx. Places glyphs one codepoint at a time, snapped to
+	/// integer pixel origins. Cheap, and correct for simple Latin text.
+	pub fn draw(&self, text: &str, pos: Vector2, size: f32, spacing: f32, color: Color) {
+		unsafe { DrawTextEx(self.raylib_font, crate::rl_str!(text), pos, size, spacing, color); }
+	}
+	/// Draws `text` one codepoint at a time via `DrawTextCodepoint`, advancing the pen
+	/// by each glyph's own (cached) width instead of snapping the whole run to
+	/// `DrawTextEx`'s internal integer layout, so horizontal spacing stays accurate at
+	/// fractional pen positions and small sizes. Glyph index and advance lookups are
+	/// cached per codepoint so repeated draws don't re-measure every frame.
+	pub fn draw_shaped(&mut self, text: &str, pos: Vector2, size: f32, spacing: f32, color: Color) {
+		if !self.shaping {
+			self.draw(text, pos, size, spacing, color);
+			return;
+		}
+
+		let scale = size / self.base_size;
+		let mut pen_x = pos.x;
+
+		for c in text.chars() {
+			let codepoint = c as u32;
+			let metrics = *self.glyph_cache.entry(codepoint).or_insert_with(|| {
+				measure_glyph(self.raylib_font, codepoint, self.base_size)
+			});
+
+			unsafe {
+				DrawTextCodepoint(self.raylib_font, codepoint as i32, Vector2{ x: pen_x, y: pos.y }, size, color);
+			}
+
+			pen_x += metrics.advance * scale + spacing;
+		}
+	}
+}
+
+/// Looks up `codepoint`'s glyph index and measures its advance width at `base_size`.
+fn measure_glyph(font: RLFont, codepoint: u32, base_size: f32) -> GlyphMetrics {
+	let glyph_id = unsafe { GetGlyphIndex(font, codepoint as i32) };
+
+	let text = match char::from_u32(codepoint) {
+		Some(c) => c.to_string(),
+		None => return GlyphMetrics{ glyph_id, advance: 0.0 },
+	};
+	let advance = unsafe { MeasureTextEx(font, crate::rl_str!(text.as_str()), base_size, 0.0).x };
+
+	GlyphMetrics{ glyph_id, advance }
+}
+
+/// Opaque mirror of raylib's `Font` value type.
+pub(crate) type RLFont = [u8; 0];
+
+extern "C" { fn GetFontDefault() -> RLFont; }
+extern "C" { fn LoadFont(path: *const i8) -> RLFont; }
+extern "C" { fn DrawTextEx(font: RLFont, text: *const i8, pos: Vector2, size: f32, spacing: f32, color: Color); }
+extern "C" { fn GetGlyphIndex(font: RLFont, codepoint: i32) -> i32; }
+extern "C" { fn MeasureTextEx(font: RLFont, text: *const i8, font_size: f32, spacing: f32) -> Vector2; }
+extern "C" { fn DrawTextCodepoint(font: RLFont, codepoint: i32, pos: Vector2, font_size: f32, tint: Color); }